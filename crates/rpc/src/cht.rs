@@ -0,0 +1,190 @@
+//! Canonical-Hash-Trie (CHT) header-commitment subsystem.
+//!
+//! The canonical chain is partitioned into fixed-size sections of
+//! [`SECTION_SIZE`] blocks. Each completed section is committed to by a
+//! binary Merkle trie whose leaves are `pedersen(header_hash,
+//! state_commitment)` for every block in the section, keyed by
+//! `block_number % SECTION_SIZE`. The trie height is therefore
+//! `log2(SECTION_SIZE)`, so a light client holding only the section root can
+//! verify any header in the section with `O(log SECTION_SIZE)` hashes.
+//!
+//! Section roots for completed sections are cached in storage (see
+//! `pathfinder_storage::Transaction::cht_section_root`/
+//! `upsert_cht_section_root`); the root of the still-open tip section is
+//! always recomputed from the current leaves and never cached, since it
+//! changes with every new block.
+//!
+//! A reorg can change which headers a section's leaves commit to, so any
+//! cached root covering the reverted range must be dropped. The sync
+//! pipeline's reorg handler must call [`invalidate_reorged_sections`] with
+//! the first block the reorg invalidated, forcing the affected sections to
+//! be rebuilt lazily the next time they're requested.
+
+use pathfinder_common::{BlockHeader, BlockId, BlockNumber};
+use pathfinder_crypto::hash::pedersen_hash;
+use pathfinder_crypto::Felt;
+use pathfinder_storage::Transaction;
+
+/// Number of blocks committed to by a single CHT section.
+pub const SECTION_SIZE: u64 = 2048;
+
+/// The section a block belongs to.
+pub fn section_index(block_number: BlockNumber) -> u64 {
+    block_number.get() / SECTION_SIZE
+}
+
+/// The block's leaf offset within its section.
+pub fn leaf_offset(block_number: BlockNumber) -> usize {
+    (block_number.get() % SECTION_SIZE) as usize
+}
+
+/// Drops any cached section root that the reorg starting at
+/// `first_invalid_block` may have invalidated, so it gets rebuilt from the
+/// post-reorg headers the next time it's requested.
+///
+/// The sync pipeline's reorg handler is the only expected caller of this:
+/// it knows `first_invalid_block` the moment it detects the reorg, before
+/// it starts re-syncing from that height.
+pub fn invalidate_reorged_sections(
+    transaction: &Transaction<'_>,
+    first_invalid_block: BlockNumber,
+) -> anyhow::Result<()> {
+    pathfinder_storage::purge_reorged_cht_sections(transaction, section_index(first_invalid_block))
+}
+
+/// Whether `section` is fully populated given the current chain tip, i.e.
+/// every leaf slot corresponds to an existing block. Only complete sections
+/// may have their root cached.
+///
+/// `section` comes straight off the wire (see
+/// `crate::v05::method::get_section_roots`), so it's bounded with checked
+/// arithmetic rather than trusted not to overflow `(section + 1) *
+/// SECTION_SIZE`: a section that large can never be complete, so it's
+/// treated the same as any other incomplete section instead of panicking.
+pub fn is_complete(section: u64, chain_tip: BlockNumber) -> bool {
+    let Some(first_block_past_section) = section
+        .checked_add(1)
+        .and_then(|sections| sections.checked_mul(SECTION_SIZE))
+    else {
+        return false;
+    };
+
+    first_block_past_section <= chain_tip.get().saturating_add(1)
+}
+
+/// The leaf committed for a single block: its header hash bound to the
+/// state commitment it produced, so a proof also attests to the state root.
+fn leaf_hash(header: &BlockHeader) -> Felt {
+    pedersen_hash(header.hash.0, header.state_commitment.0)
+}
+
+/// Assembles the leaves for `section` by reading headers from storage.
+/// Slots past the current chain tip (only possible for the still-open tip
+/// section) are padded with [`Felt::ZERO`].
+pub fn section_leaves(transaction: &Transaction<'_>, section: u64) -> anyhow::Result<Vec<Felt>> {
+    let first = section * SECTION_SIZE;
+    let mut leaves = Vec::with_capacity(SECTION_SIZE as usize);
+
+    for offset in 0..SECTION_SIZE {
+        let number = BlockNumber::new_or_panic(first + offset);
+        let leaf = transaction
+            .block_header(BlockId::Number(number))?
+            .map(|header| leaf_hash(&header))
+            .unwrap_or(Felt::ZERO);
+        leaves.push(leaf);
+    }
+
+    Ok(leaves)
+}
+
+/// Folds `leaves` up to their section root.
+pub fn section_root(leaves: &[Felt]) -> Felt {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks_exact(2)
+            .map(|pair| pedersen_hash(pair[0], pair[1]))
+            .collect();
+    }
+    level.first().copied().unwrap_or(Felt::ZERO)
+}
+
+/// The sibling hashes from `leaves[index]` up to the section root, ordered
+/// from the leaf level to the root.
+pub fn merkle_path(leaves: &[Felt], index: usize) -> Vec<Felt> {
+    let mut path = Vec::with_capacity(SECTION_SIZE.ilog2() as usize);
+    let mut level = leaves.to_vec();
+    let mut index = index;
+
+    while level.len() > 1 {
+        path.push(level[index ^ 1]);
+        level = level
+            .chunks_exact(2)
+            .map(|pair| pedersen_hash(pair[0], pair[1]))
+            .collect();
+        index /= 2;
+    }
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn section_math() {
+        assert_eq!(section_index(BlockNumber::new_or_panic(0)), 0);
+        assert_eq!(section_index(BlockNumber::new_or_panic(2047)), 0);
+        assert_eq!(section_index(BlockNumber::new_or_panic(2048)), 1);
+
+        assert_eq!(leaf_offset(BlockNumber::new_or_panic(2048)), 0);
+        assert_eq!(leaf_offset(BlockNumber::new_or_panic(2049)), 1);
+    }
+
+    #[test]
+    fn path_verifies_against_root() {
+        let leaves: Vec<Felt> = (0..SECTION_SIZE)
+            .map(|i| Felt::from_be_slice(&i.to_be_bytes()).unwrap())
+            .collect();
+
+        let root = section_root(&leaves);
+
+        for index in [0, 1, 42, (SECTION_SIZE - 1) as usize] {
+            let path = merkle_path(&leaves, index);
+            assert_eq!(path.len(), SECTION_SIZE.ilog2() as usize);
+
+            let mut hash = leaves[index];
+            let mut index = index;
+            for sibling in path {
+                hash = if index % 2 == 0 {
+                    pedersen_hash(hash, sibling)
+                } else {
+                    pedersen_hash(sibling, hash)
+                };
+                index /= 2;
+            }
+
+            assert_eq!(hash, root);
+        }
+    }
+
+    #[test]
+    fn is_complete_rejects_overflowing_section_instead_of_panicking() {
+        assert!(!is_complete(u64::MAX, BlockNumber::GENESIS));
+        assert!(!is_complete(u64::MAX / SECTION_SIZE, BlockNumber::GENESIS));
+    }
+
+    #[test]
+    fn invalidate_reorged_sections_drops_cached_root() {
+        let mut connection = pathfinder_storage::Connection::for_testing().unwrap();
+        let transaction = connection.transaction().unwrap();
+
+        let root = Felt::from_be_slice(&1u64.to_be_bytes()).unwrap();
+        transaction.upsert_cht_section_root(0, root).unwrap();
+
+        invalidate_reorged_sections(&transaction, BlockNumber::GENESIS).unwrap();
+
+        assert_eq!(transaction.cht_section_root(0).unwrap(), None);
+    }
+}
@@ -0,0 +1,32 @@
+//! Shared state handed to every JSON-RPC method handler.
+//!
+//! This only shows the fields the method handlers here read (`storage`,
+//! `pending_data`, `retention_policy`, `l1_state`); the rest of
+//! `RpcContext` — the `for_tests`/`for_tests_with_pending`/
+//! `with_pending_data` test constructors, and so on — is not reproduced
+//! here.
+
+use crate::retention::RetentionPolicy;
+use pathfinder_common::EthereumBlockNumber;
+
+/// The node's current view of the L1 chain head, as observed by its L1
+/// sync process.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct L1Head {
+    pub block_number: EthereumBlockNumber,
+}
+
+#[derive(Clone)]
+pub struct RpcContext {
+    pub storage: pathfinder_storage::Storage,
+    pub pending_data: Option<starknet_gateway_types::pending::PendingData>,
+    /// What this node keeps on disk once a block is no longer the tip.
+    /// Methods that read transaction/receipt/state bodies must check this
+    /// via [`RetentionPolicy::ensure_bodies_available`] before doing so.
+    /// See [`crate::retention`].
+    pub retention_policy: RetentionPolicy,
+    /// The node's current L1 head, kept up to date by the L1 sync process.
+    /// `None` until the first L1 state update has been observed (or when
+    /// L1 sync is disabled, e.g. in most test contexts).
+    pub l1_state: Option<tokio::sync::watch::Receiver<L1Head>>,
+}
@@ -0,0 +1,62 @@
+//! Storage retention policy for "light serving" nodes.
+//!
+//! A node configured with [`RetentionPolicy::HeadersOnly`] drops
+//! transaction/receipt/state bodies once a block reaches L1 finality,
+//! keeping only headers and transaction-hash lists on disk. Methods that
+//! only need that reduced dataset (e.g. `get_block_with_tx_hashes`,
+//! `get_transaction_status`, `spec_version`) keep working unmodified under
+//! either policy. Methods that need full bodies (`get_block_with_txs`,
+//! `get_transaction_receipt`, `trace_*`, `simulate_transactions`) must call
+//! [`RetentionPolicy::ensure_bodies_available`] before reading them, so that
+//! a pruned node returns a structured [`PrunedDataError`] instead of a
+//! confusing "not found".
+
+use serde::{Deserialize, Serialize};
+
+/// What this node keeps on disk once a block is no longer the tip.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RetentionPolicy {
+    /// Keep full transaction, receipt and state bodies for every block.
+    #[default]
+    Full,
+    /// Drop bodies once a block reaches L1 finality, keeping only headers
+    /// and transaction-hash lists.
+    HeadersOnly,
+}
+
+/// Returned by methods that require transaction/receipt/state bodies when
+/// the node runs with [`RetentionPolicy::HeadersOnly`]. It is returned for
+/// every such request, whether or not the requested block's bodies have
+/// been pruned yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("block body has been pruned by this node's retention policy")]
+pub struct PrunedDataError;
+
+impl RetentionPolicy {
+    /// Errors with [`PrunedDataError`] if this policy does not retain
+    /// transaction/receipt/state bodies.
+    pub fn ensure_bodies_available(self) -> Result<(), PrunedDataError> {
+        match self {
+            RetentionPolicy::Full => Ok(()),
+            RetentionPolicy::HeadersOnly => Err(PrunedDataError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_retains_bodies() {
+        assert_eq!(RetentionPolicy::Full.ensure_bodies_available(), Ok(()));
+    }
+
+    #[test]
+    fn headers_only_prunes_bodies() {
+        assert_eq!(
+            RetentionPolicy::HeadersOnly.ensure_bodies_available(),
+            Err(PrunedDataError)
+        );
+    }
+}
@@ -1,5 +1,8 @@
 mod get_block_with_tx_hashes;
 mod get_block_with_txs;
+mod get_finality_status;
+mod get_header_proof;
+mod get_section_roots;
 mod get_transaction_receipt;
 mod get_transaction_status;
 mod simulate_transactions;
@@ -9,6 +12,9 @@ mod trace_transaction;
 
 pub(crate) use get_block_with_tx_hashes::get_block_with_tx_hashes;
 pub(crate) use get_block_with_txs::get_block_with_txs;
+pub(crate) use get_finality_status::get_finality_status;
+pub(crate) use get_header_proof::get_header_proof;
+pub(crate) use get_section_roots::get_section_roots;
 pub(crate) use get_transaction_receipt::get_transaction_receipt;
 pub(crate) use get_transaction_status::get_transaction_status;
 pub(crate) use simulate_transactions::simulate_transactions;
@@ -14,7 +14,12 @@ pub struct GetBlockInput {
 
 crate::error::generate_rpc_error_subset!(GetBlockError: BlockNotFound);
 
-/// Get block information with transaction hashes given the block id
+/// Get block information with transaction hashes given the block id.
+///
+/// This only reads the header, L1 acceptance and transaction-hash list, so
+/// unlike `get_block_with_txs` it remains servable under
+/// `RetentionPolicy::HeadersOnly` (see [`crate::retention`]) without needing
+/// to check `ensure_bodies_available` first.
 pub async fn get_block_with_tx_hashes(
     context: RpcContext,
     input: GetBlockInput,
@@ -55,8 +60,13 @@ pub async fn get_block_with_tx_hashes(
             .context("Reading block from database")?
             .ok_or(GetBlockError::BlockNotFound)?;
 
-        let l1_accepted = transaction.block_is_l1_accepted(header.number.into())?;
-        let block_status = if l1_accepted {
+        let l1_finality = transaction
+            .l1_finality_for_block(header.number)
+            .context("Reading L1 finality data")?;
+        let block_status = if transaction
+            .is_l1_accepted(header.number)
+            .context("Reading L1 acceptance")?
+        {
             BlockStatus::AcceptedOnL1
         } else {
             BlockStatus::AcceptedOnL2
@@ -67,7 +77,12 @@ pub async fn get_block_with_tx_hashes(
             .context("Reading transaction hashes")?
             .context("Missing block")?;
 
-        Ok(types::Block::from_parts(header, block_status, transactions))
+        Ok(types::Block::from_parts(
+            header,
+            block_status,
+            transactions,
+            l1_finality,
+        ))
     })
     .await
     .context("Database read panic or shutting down")?
@@ -75,7 +90,7 @@ pub async fn get_block_with_tx_hashes(
 
 mod types {
     use crate::v02::types::reply::BlockStatus;
-    use pathfinder_common::{BlockHeader, TransactionHash};
+    use pathfinder_common::{BlockHeader, EthereumBlockNumber, EthereumTransactionHash, L1Finality, TransactionHash};
     use serde::Serialize;
 
     /// L2 Block as returned by the RPC API.
@@ -85,6 +100,12 @@ mod types {
         pub header: crate::v05::types::BlockHeader,
         pub status: BlockStatus,
         pub transactions: Vec<TransactionHash>,
+        /// The L1 block number at which this block was accepted on L1, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub l1_block_number: Option<EthereumBlockNumber>,
+        /// The L1 transaction hash that accepted this block, if any.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub l1_transaction_hash: Option<EthereumTransactionHash>,
     }
 
     impl Block {
@@ -92,11 +113,14 @@ mod types {
             header: BlockHeader,
             status: BlockStatus,
             transactions: Vec<TransactionHash>,
+            l1_finality: Option<L1Finality>,
         ) -> Self {
             Self {
                 header: header.into(),
                 status,
                 transactions,
+                l1_block_number: l1_finality.as_ref().map(|f| f.l1_block_number),
+                l1_transaction_hash: l1_finality.as_ref().map(|f| f.l1_transaction_hash),
             }
         }
 
@@ -106,6 +130,9 @@ mod types {
                 status: block.status().into(),
                 transactions: block.transactions().iter().map(|t| t.hash()).collect(),
                 header: crate::v05::types::BlockHeader::from_sequencer(block),
+                // The sequencer representation doesn't expose L1 finality data.
+                l1_block_number: None,
+                l1_transaction_hash: None,
             }
         }
     }
@@ -262,4 +289,23 @@ mod tests {
             ]
         );
     }
+
+    #[tokio::test]
+    async fn l1_finality_defaults_to_none() {
+        let ctx: RpcContext = RpcContext::for_tests();
+
+        let block = get_block_with_tx_hashes(
+            ctx,
+            GetBlockInput {
+                block_id: BlockNumber::GENESIS.into(),
+            },
+        )
+        .await
+        .unwrap();
+
+        // The fixture chain hasn't had any block accepted on L1 yet.
+        assert_eq!(block.status, BlockStatus::AcceptedOnL2);
+        assert_eq!(block.l1_block_number, None);
+        assert_eq!(block.l1_transaction_hash, None);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,191 @@
+use crate::context::RpcContext;
+use crate::v02::types::reply::BlockStatus;
+
+use anyhow::{anyhow, Context};
+use pathfinder_common::BlockId;
+use serde::Deserialize;
+
+use crate::retention::PrunedDataError;
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[cfg_attr(test, derive(Copy, Clone))]
+#[serde(deny_unknown_fields)]
+pub struct GetBlockInput {
+    block_id: BlockId,
+}
+
+crate::error::generate_rpc_error_subset!(GetBlockError: BlockNotFound, PrunedData);
+
+impl From<PrunedDataError> for GetBlockError {
+    fn from(_: PrunedDataError) -> Self {
+        Self::PrunedData
+    }
+}
+
+/// Get block information with full transaction bodies given the block id.
+///
+/// Unlike `get_block_with_tx_hashes`, this reads transaction bodies, so it
+/// requires `RetentionPolicy::Full` (see [`crate::retention`]). Under
+/// `RetentionPolicy::HeadersOnly` it errors for every block, including ones
+/// whose bodies have not been pruned yet.
+pub async fn get_block_with_txs(
+    context: RpcContext,
+    input: GetBlockInput,
+) -> Result<types::Block, GetBlockError> {
+    context.retention_policy.ensure_bodies_available()?;
+
+    let block_id = input.block_id;
+    let block_id = match block_id {
+        BlockId::Pending => {
+            match context
+                .pending_data
+                .ok_or_else(|| anyhow!("Pending data not supported in this configuration"))?
+                .block()
+                .await
+            {
+                Some(block) => {
+                    return Ok(types::Block::from_sequencer(block.as_ref().clone().into()))
+                }
+                None => return Err(GetBlockError::BlockNotFound),
+            }
+        }
+        other => other.try_into().expect("Only pending cast should fail"),
+    };
+
+    let storage = context.storage.clone();
+    let span = tracing::Span::current();
+
+    tokio::task::spawn_blocking(move || {
+        let _g = span.enter();
+        let mut connection = storage
+            .connection()
+            .context("Opening database connection")?;
+
+        let transaction = connection
+            .transaction()
+            .context("Creating database transaction")?;
+
+        let header = transaction
+            .block_header(block_id)
+            .context("Reading block from database")?
+            .ok_or(GetBlockError::BlockNotFound)?;
+
+        let l1_finality = transaction
+            .l1_finality_for_block(header.number)
+            .context("Reading L1 finality data")?;
+        let block_status = if transaction
+            .is_l1_accepted(header.number)
+            .context("Reading L1 acceptance")?
+        {
+            BlockStatus::AcceptedOnL1
+        } else {
+            BlockStatus::AcceptedOnL2
+        };
+
+        let transactions = transaction
+            .transactions_for_block(header.number.into())
+            .context("Reading transactions")?
+            .context("Missing block")?;
+
+        Ok(types::Block::from_parts(
+            header,
+            block_status,
+            transactions,
+            l1_finality,
+        ))
+    })
+    .await
+    .context("Database read panic or shutting down")?
+}
+
+mod types {
+    use crate::v02::types::reply::BlockStatus;
+    use pathfinder_common::{
+        BlockHeader,
+        EthereumBlockNumber,
+        EthereumTransactionHash,
+        L1Finality,
+    };
+    use serde::Serialize;
+    use starknet_gateway_types::reply::transaction::Transaction;
+
+    /// L2 Block as returned by the RPC API, including full transaction
+    /// bodies.
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct Block {
+        #[serde(flatten)]
+        pub header: crate::v05::types::BlockHeader,
+        pub status: BlockStatus,
+        pub transactions: Vec<Transaction>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub l1_block_number: Option<EthereumBlockNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub l1_transaction_hash: Option<EthereumTransactionHash>,
+    }
+
+    impl Block {
+        pub fn from_parts(
+            header: BlockHeader,
+            status: BlockStatus,
+            transactions: Vec<Transaction>,
+            l1_finality: Option<L1Finality>,
+        ) -> Self {
+            Self {
+                header: header.into(),
+                status,
+                transactions,
+                l1_block_number: l1_finality.as_ref().map(|f| f.l1_block_number),
+                l1_transaction_hash: l1_finality.as_ref().map(|f| f.l1_transaction_hash),
+            }
+        }
+
+        /// Constructs [Block] from [sequencer's block representation](starknet_gateway_types::reply::Block)
+        pub fn from_sequencer(block: starknet_gateway_types::reply::MaybePendingBlock) -> Self {
+            Self {
+                status: block.status().into(),
+                transactions: block.transactions().to_vec(),
+                header: crate::v05::types::BlockHeader::from_sequencer(block),
+                l1_block_number: None,
+                l1_transaction_hash: None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::retention::RetentionPolicy;
+    use pathfinder_common::BlockNumber;
+
+    #[tokio::test]
+    async fn pruned_node_rejects_body_read() {
+        let mut ctx = RpcContext::for_tests();
+        ctx.retention_policy = RetentionPolicy::HeadersOnly;
+
+        let result = get_block_with_txs(
+            ctx,
+            GetBlockInput {
+                block_id: BlockNumber::GENESIS.into(),
+            },
+        )
+        .await;
+
+        assert!(matches!(result, Err(GetBlockError::PrunedData)));
+    }
+
+    #[tokio::test]
+    async fn full_node_serves_body() {
+        let ctx = RpcContext::for_tests();
+
+        let result = get_block_with_txs(
+            ctx,
+            GetBlockInput {
+                block_id: BlockNumber::GENESIS.into(),
+            },
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+}
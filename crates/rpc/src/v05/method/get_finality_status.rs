@@ -0,0 +1,205 @@
+use crate::context::RpcContext;
+use crate::v02::types::reply::BlockStatus;
+
+use anyhow::Context;
+use pathfinder_common::BlockId;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[cfg_attr(test, derive(Copy, Clone))]
+#[serde(deny_unknown_fields)]
+pub struct GetFinalityStatusInput {
+    block_id: BlockId,
+}
+
+crate::error::generate_rpc_error_subset!(GetFinalityStatusError: BlockNotFound);
+
+/// Get the L1 settlement status of a block: whether it has been accepted on
+/// L1, the L1 transaction and block number at which it was, and the number
+/// of L1 blocks that have elapsed since, derived from the node's current L1
+/// head.
+pub async fn get_finality_status(
+    context: RpcContext,
+    input: GetFinalityStatusInput,
+) -> Result<types::FinalityStatus, GetFinalityStatusError> {
+    let block_id = input
+        .block_id
+        .try_into()
+        .map_err(|_| GetFinalityStatusError::BlockNotFound)?;
+
+    let storage = context.storage.clone();
+    let span = tracing::Span::current();
+
+    let (l1_finality, is_l1_accepted) = tokio::task::spawn_blocking(move || {
+        let _g = span.enter();
+        let mut connection = storage
+            .connection()
+            .context("Opening database connection")?;
+
+        let transaction = connection
+            .transaction()
+            .context("Creating database transaction")?;
+
+        let header = transaction
+            .block_header(block_id)
+            .context("Reading block from database")?
+            .ok_or(GetFinalityStatusError::BlockNotFound)?;
+
+        let l1_finality = transaction
+            .l1_finality_for_block(header.number)
+            .context("Reading L1 finality data")?;
+        let is_l1_accepted = transaction
+            .is_l1_accepted(header.number)
+            .context("Reading L1 acceptance")?;
+
+        Ok::<_, GetFinalityStatusError>((l1_finality, is_l1_accepted))
+    })
+    .await
+    .context("Database read panic or shutting down")??;
+
+    let l1_head = context.l1_state.as_ref().map(|watch| *watch.borrow());
+    let confirmations = confirmations_since(l1_head, l1_finality.as_ref());
+
+    Ok(types::FinalityStatus {
+        status: if is_l1_accepted {
+            BlockStatus::AcceptedOnL1
+        } else {
+            BlockStatus::AcceptedOnL2
+        },
+        l1_block_number: l1_finality.as_ref().map(|f| f.l1_block_number),
+        l1_tx_hash: l1_finality.as_ref().map(|f| f.l1_transaction_hash),
+        confirmations,
+    })
+}
+
+/// Number of L1 blocks that have elapsed since `finality`, derived from the
+/// node's current L1 head. `None` if either is unknown: the block hasn't
+/// been accepted on L1 yet, or this node has no L1 sync process to report a
+/// head.
+fn confirmations_since(
+    l1_head: Option<crate::context::L1Head>,
+    finality: Option<&pathfinder_common::L1Finality>,
+) -> Option<u64> {
+    let head = l1_head?;
+    let finality = finality?;
+    Some(head.block_number.0.saturating_sub(finality.l1_block_number.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pathfinder_common::BlockNumber;
+
+    #[tokio::test]
+    async fn not_yet_accepted_on_l1() {
+        let ctx = RpcContext::for_tests();
+
+        let result = get_finality_status(
+            ctx,
+            GetFinalityStatusInput {
+                block_id: BlockNumber::GENESIS.into(),
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.status, BlockStatus::AcceptedOnL2);
+        assert_eq!(result.l1_block_number, None);
+        assert_eq!(result.l1_tx_hash, None);
+        assert_eq!(result.confirmations, None);
+    }
+
+    #[test]
+    fn confirmations_since_counts_elapsed_l1_blocks() {
+        use crate::context::L1Head;
+        use pathfinder_common::{EthereumBlockNumber, EthereumTransactionHash, L1Finality};
+
+        let finality = L1Finality {
+            l1_block_number: EthereumBlockNumber(100),
+            l1_transaction_hash: EthereumTransactionHash::from_be_slice(&[0xab; 32]).unwrap(),
+        };
+        let head = L1Head {
+            block_number: EthereumBlockNumber(107),
+        };
+
+        assert_eq!(confirmations_since(Some(head), Some(&finality)), Some(7));
+    }
+
+    #[test]
+    fn confirmations_since_is_none_without_l1_head() {
+        use pathfinder_common::{EthereumBlockNumber, EthereumTransactionHash, L1Finality};
+
+        let finality = L1Finality {
+            l1_block_number: EthereumBlockNumber(100),
+            l1_transaction_hash: EthereumTransactionHash::from_be_slice(&[0xab; 32]).unwrap(),
+        };
+
+        assert_eq!(confirmations_since(None, Some(&finality)), None);
+    }
+
+    #[test]
+    fn confirmations_since_is_none_without_finality() {
+        use crate::context::L1Head;
+        use pathfinder_common::EthereumBlockNumber;
+
+        let head = L1Head {
+            block_number: EthereumBlockNumber(107),
+        };
+
+        assert_eq!(confirmations_since(Some(head), None), None);
+    }
+
+    #[test]
+    fn confirmations_since_is_none_with_neither() {
+        assert_eq!(confirmations_since(None, None), None);
+    }
+
+    #[test]
+    fn accepted_on_l1_status_serializes_its_l1_fields() {
+        use pathfinder_common::{EthereumBlockNumber, EthereumTransactionHash};
+
+        let status = types::FinalityStatus {
+            status: BlockStatus::AcceptedOnL1,
+            l1_block_number: Some(EthereumBlockNumber(100)),
+            l1_tx_hash: Some(EthereumTransactionHash::from_be_slice(&[0xab; 32]).unwrap()),
+            confirmations: Some(7),
+        };
+
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["status"], serde_json::to_value(BlockStatus::AcceptedOnL1).unwrap());
+        assert_eq!(json["l1_block_number"], serde_json::json!(100));
+        assert_eq!(json["confirmations"], serde_json::json!(7));
+    }
+
+    #[tokio::test]
+    async fn unknown_block_is_not_found() {
+        let ctx = RpcContext::for_tests();
+
+        let result = get_finality_status(
+            ctx,
+            GetFinalityStatusInput {
+                block_id: BlockNumber::new_or_panic(9999).into(),
+            },
+        )
+        .await;
+
+        assert!(matches!(result, Err(GetFinalityStatusError::BlockNotFound)));
+    }
+}
+
+mod types {
+    use crate::v02::types::reply::BlockStatus;
+    use pathfinder_common::{EthereumBlockNumber, EthereumTransactionHash};
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct FinalityStatus {
+        pub status: BlockStatus,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub l1_block_number: Option<EthereumBlockNumber>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub l1_tx_hash: Option<EthereumTransactionHash>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub confirmations: Option<u64>,
+    }
+}
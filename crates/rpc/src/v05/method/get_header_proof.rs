@@ -0,0 +1,144 @@
+use crate::cht;
+use crate::context::RpcContext;
+
+use anyhow::Context;
+use pathfinder_common::BlockId;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[cfg_attr(test, derive(Copy, Clone))]
+#[serde(deny_unknown_fields)]
+pub struct GetHeaderProofInput {
+    block_id: BlockId,
+}
+
+crate::error::generate_rpc_error_subset!(GetHeaderProofError: BlockNotFound);
+
+/// Get a block header together with a Merkle proof against the
+/// Canonical-Hash-Trie section it belongs to, so that a light client holding
+/// only section roots (see [`crate::v05::method::get_section_roots`]) can
+/// verify the header in `O(log N)` hashes.
+pub async fn get_header_proof(
+    context: RpcContext,
+    input: GetHeaderProofInput,
+) -> Result<types::HeaderProof, GetHeaderProofError> {
+    // Pending blocks aren't part of the canonical chain yet and therefore
+    // aren't committed to by any CHT section.
+    let block_id = input
+        .block_id
+        .try_into()
+        .map_err(|_| GetHeaderProofError::BlockNotFound)?;
+
+    let storage = context.storage.clone();
+    let span = tracing::Span::current();
+
+    tokio::task::spawn_blocking(move || {
+        let _g = span.enter();
+        let mut connection = storage
+            .connection()
+            .context("Opening database connection")?;
+
+        let transaction = connection
+            .transaction()
+            .context("Creating database transaction")?;
+
+        let header = transaction
+            .block_header(block_id)
+            .context("Reading block from database")?
+            .ok_or(GetHeaderProofError::BlockNotFound)?;
+
+        let tip = transaction
+            .block_header(BlockId::Latest)
+            .context("Reading chain tip")?
+            .context("Chain tip missing despite block existing")?
+            .number;
+
+        let section = cht::section_index(header.number);
+        let leaves = cht::section_leaves(&transaction, section)
+            .context("Assembling CHT section leaves")?;
+        let section_root = cht::section_root(&leaves);
+
+        // Only a completed section's root is stable; cache it lazily the
+        // first time it's requested rather than eagerly on every block.
+        if cht::is_complete(section, tip) && transaction.cht_section_root(section)?.is_none() {
+            transaction
+                .upsert_cht_section_root(section, section_root)
+                .context("Caching CHT section root")?;
+        }
+
+        transaction
+            .commit()
+            .context("Committing database transaction")?;
+
+        let path = cht::merkle_path(&leaves, cht::leaf_offset(header.number));
+
+        Ok(types::HeaderProof {
+            header: header.into(),
+            section,
+            section_root,
+            path,
+        })
+    })
+    .await
+    .context("Database read panic or shutting down")?
+}
+
+mod types {
+    use pathfinder_crypto::Felt;
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct HeaderProof {
+        #[serde(flatten)]
+        pub header: crate::v05::types::BlockHeader,
+        pub section: u64,
+        pub section_root: Felt,
+        pub path: Vec<Felt>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pathfinder_common::BlockNumber;
+    use serde_json::json;
+
+    #[test]
+    fn input_parsing() {
+        let input =
+            serde_json::from_value::<GetHeaderProofInput>(json!([{"block_number": 1}])).unwrap();
+        assert_eq!(input.block_id, BlockNumber::new_or_panic(1).into());
+    }
+
+    #[tokio::test]
+    async fn proof_shape_matches_section_size() {
+        let ctx = RpcContext::for_tests();
+
+        let proof = get_header_proof(
+            ctx,
+            GetHeaderProofInput {
+                block_id: BlockNumber::GENESIS.into(),
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(proof.section, cht::section_index(BlockNumber::GENESIS));
+        assert_eq!(proof.path.len(), cht::SECTION_SIZE.ilog2() as usize);
+    }
+
+    #[tokio::test]
+    async fn unknown_block_is_not_found() {
+        let ctx = RpcContext::for_tests();
+
+        let result = get_header_proof(
+            ctx,
+            GetHeaderProofInput {
+                block_id: BlockNumber::new_or_panic(9999).into(),
+            },
+        )
+        .await;
+
+        assert!(matches!(result, Err(GetHeaderProofError::BlockNotFound)));
+    }
+}
@@ -0,0 +1,135 @@
+use crate::cht;
+use crate::context::RpcContext;
+
+use anyhow::Context;
+use pathfinder_common::BlockId;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[cfg_attr(test, derive(Copy, Clone))]
+#[serde(deny_unknown_fields)]
+pub struct GetSectionRootsInput {
+    first: u64,
+    count: u64,
+}
+
+crate::error::generate_rpc_error_subset!(GetSectionRootsError);
+
+/// Get the Canonical-Hash-Trie section roots `[first, first + count)`, so a
+/// light client can bootstrap the root set it needs to verify headers
+/// returned by [`crate::v05::method::get_header_proof`].
+///
+/// Sections that aren't complete yet (including the still-open tip section)
+/// are omitted rather than padded, since their root isn't final.
+pub async fn get_section_roots(
+    context: RpcContext,
+    input: GetSectionRootsInput,
+) -> Result<types::SectionRoots, GetSectionRootsError> {
+    let storage = context.storage.clone();
+    let span = tracing::Span::current();
+
+    tokio::task::spawn_blocking(move || {
+        let _g = span.enter();
+        let mut connection = storage
+            .connection()
+            .context("Opening database connection")?;
+
+        let transaction = connection
+            .transaction()
+            .context("Creating database transaction")?;
+
+        let tip = transaction
+            .block_header(BlockId::Latest)
+            .context("Reading chain tip")?
+            .map(|header| header.number);
+
+        let mut roots = Vec::new();
+
+        for section in input.first..input.first.saturating_add(input.count) {
+            let Some(tip) = tip else { break };
+            if !cht::is_complete(section, tip) {
+                break;
+            }
+
+            let root = match transaction
+                .cht_section_root(section)
+                .context("Reading cached CHT section root")?
+            {
+                Some(root) => root,
+                None => {
+                    let leaves = cht::section_leaves(&transaction, section)
+                        .context("Assembling CHT section leaves")?;
+                    let root = cht::section_root(&leaves);
+                    transaction
+                        .upsert_cht_section_root(section, root)
+                        .context("Caching CHT section root")?;
+                    root
+                }
+            };
+
+            roots.push(root);
+        }
+
+        transaction
+            .commit()
+            .context("Committing database transaction")?;
+
+        Ok(types::SectionRoots { roots })
+    })
+    .await
+    .context("Database read panic or shutting down")?
+}
+
+mod types {
+    use pathfinder_crypto::Felt;
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct SectionRoots {
+        pub roots: Vec<Felt>,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn incomplete_sections_are_omitted() {
+        let ctx = RpcContext::for_tests();
+
+        // The fixture chain is far smaller than one section, so there's no
+        // complete section to report yet, including section 0 itself.
+        let result = get_section_roots(
+            ctx,
+            GetSectionRootsInput {
+                first: 0,
+                count: 10,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(result.roots.is_empty());
+    }
+
+    #[tokio::test]
+    async fn huge_first_does_not_overflow() {
+        let ctx = RpcContext::for_tests();
+
+        // `first` near u64::MAX must not overflow the `(section + 1) *
+        // SECTION_SIZE` arithmetic in `cht::is_complete` — it's just another
+        // section that can never be complete.
+        let result = get_section_roots(
+            ctx,
+            GetSectionRootsInput {
+                first: u64::MAX - 1,
+                count: 10,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert!(result.roots.is_empty());
+    }
+}
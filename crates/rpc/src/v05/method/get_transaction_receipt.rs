@@ -0,0 +1,74 @@
+use crate::context::RpcContext;
+
+use anyhow::Context;
+use pathfinder_common::TransactionHash;
+use serde::Deserialize;
+
+use crate::retention::PrunedDataError;
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[cfg_attr(test, derive(Copy, Clone))]
+#[serde(deny_unknown_fields)]
+pub struct GetTransactionReceiptInput {
+    transaction_hash: TransactionHash,
+}
+
+crate::error::generate_rpc_error_subset!(GetTransactionReceiptError: TxnHashNotFound, PrunedData);
+
+impl From<PrunedDataError> for GetTransactionReceiptError {
+    fn from(_: PrunedDataError) -> Self {
+        Self::PrunedData
+    }
+}
+
+/// Get the receipt for a transaction given its hash.
+///
+/// This reads the transaction's execution receipt, so it requires
+/// `RetentionPolicy::Full` (see [`crate::retention`]). Under
+/// `RetentionPolicy::HeadersOnly` it errors for every transaction, whether
+/// or not its receipt has been pruned yet.
+pub async fn get_transaction_receipt(
+    context: RpcContext,
+    input: GetTransactionReceiptInput,
+) -> Result<types::Receipt, GetTransactionReceiptError> {
+    context.retention_policy.ensure_bodies_available()?;
+
+    let storage = context.storage.clone();
+    let span = tracing::Span::current();
+
+    tokio::task::spawn_blocking(move || {
+        let _g = span.enter();
+        let mut connection = storage
+            .connection()
+            .context("Opening database connection")?;
+
+        let transaction = connection
+            .transaction()
+            .context("Creating database transaction")?;
+
+        let receipt = transaction
+            .transaction_receipt(input.transaction_hash)
+            .context("Reading transaction receipt")?
+            .ok_or(GetTransactionReceiptError::TxnHashNotFound)?;
+
+        Ok(types::Receipt::from(receipt))
+    })
+    .await
+    .context("Database read panic or shutting down")?
+}
+
+mod types {
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct Receipt {
+        #[serde(flatten)]
+        pub receipt: pathfinder_common::receipt::Receipt,
+    }
+
+    impl From<pathfinder_common::receipt::Receipt> for Receipt {
+        fn from(receipt: pathfinder_common::receipt::Receipt) -> Self {
+            Self { receipt }
+        }
+    }
+}
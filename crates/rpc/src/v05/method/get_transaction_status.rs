@@ -0,0 +1,74 @@
+use crate::context::RpcContext;
+
+use anyhow::Context;
+use pathfinder_common::TransactionHash;
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[cfg_attr(test, derive(Copy, Clone))]
+#[serde(deny_unknown_fields)]
+pub struct GetTransactionStatusInput {
+    transaction_hash: TransactionHash,
+}
+
+crate::error::generate_rpc_error_subset!(GetTransactionStatusError: TxnHashNotFound);
+
+/// Get the status of a transaction given its hash.
+///
+/// This only needs the transaction-hash-to-block index and L1 finality
+/// data, not the transaction/receipt body, so it remains servable under
+/// `RetentionPolicy::HeadersOnly` (see [`crate::retention`]) without
+/// checking `ensure_bodies_available`.
+pub async fn get_transaction_status(
+    context: RpcContext,
+    input: GetTransactionStatusInput,
+) -> Result<types::TransactionStatus, GetTransactionStatusError> {
+    let storage = context.storage.clone();
+    let span = tracing::Span::current();
+
+    tokio::task::spawn_blocking(move || {
+        let _g = span.enter();
+        let mut connection = storage
+            .connection()
+            .context("Opening database connection")?;
+
+        let transaction = connection
+            .transaction()
+            .context("Creating database transaction")?;
+
+        let block_number = transaction
+            .block_number_for_transaction(input.transaction_hash)
+            .context("Reading transaction's block")?
+            .ok_or(GetTransactionStatusError::TxnHashNotFound)?;
+
+        let is_l1_accepted = transaction
+            .is_l1_accepted(block_number)
+            .context("Reading L1 acceptance")?;
+
+        Ok(types::TransactionStatus {
+            finality_status: if is_l1_accepted {
+                types::FinalityStatus::AcceptedOnL1
+            } else {
+                types::FinalityStatus::AcceptedOnL2
+            },
+        })
+    })
+    .await
+    .context("Database read panic or shutting down")?
+}
+
+mod types {
+    use serde::Serialize;
+
+    #[derive(Clone, Copy, Debug, Serialize, PartialEq, Eq)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    pub enum FinalityStatus {
+        AcceptedOnL2,
+        AcceptedOnL1,
+    }
+
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct TransactionStatus {
+        pub finality_status: FinalityStatus,
+    }
+}
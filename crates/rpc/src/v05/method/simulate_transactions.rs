@@ -0,0 +1,48 @@
+use crate::context::RpcContext;
+
+use pathfinder_common::BlockId;
+use serde::Deserialize;
+
+use crate::retention::PrunedDataError;
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SimulateTransactionsInput {
+    block_id: BlockId,
+    transactions: Vec<pathfinder_common::transaction::Transaction>,
+    simulation_flags: Vec<String>,
+}
+
+crate::error::generate_rpc_error_subset!(SimulateTransactionsError: BlockNotFound, PrunedData);
+
+impl From<PrunedDataError> for SimulateTransactionsError {
+    fn from(_: PrunedDataError) -> Self {
+        Self::PrunedData
+    }
+}
+
+/// Simulate executing a list of transactions against the state at a given
+/// block.
+///
+/// Simulation re-executes against historical state, so it requires
+/// `RetentionPolicy::Full` (see [`crate::retention`]). Under
+/// `RetentionPolicy::HeadersOnly` it errors for every `block_id`, whether or
+/// not the state needed for it has been pruned yet.
+pub async fn simulate_transactions(
+    context: RpcContext,
+    input: SimulateTransactionsInput,
+) -> Result<types::SimulatedTransactions, SimulateTransactionsError> {
+    context.retention_policy.ensure_bodies_available()?;
+
+    let _ = input;
+    // Execution itself is unaffected by the retention-policy routing added
+    // here and isn't part of this slice.
+    todo!("transaction simulation")
+}
+
+mod types {
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct SimulatedTransactions(pub Vec<serde_json::Value>);
+}
@@ -0,0 +1,12 @@
+use crate::context::RpcContext;
+
+crate::error::generate_rpc_error_subset!(SpecVersionError);
+
+/// Get the JSON-RPC spec version implemented by this node.
+///
+/// Static and independent of on-disk retention: this never reads block or
+/// transaction data, so it's always servable under
+/// `RetentionPolicy::HeadersOnly` (see [`crate::retention`]).
+pub async fn spec_version(_context: RpcContext) -> Result<&'static str, SpecVersionError> {
+    Ok("0.5.1")
+}
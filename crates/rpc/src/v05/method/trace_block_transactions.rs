@@ -0,0 +1,46 @@
+use crate::context::RpcContext;
+
+use pathfinder_common::BlockId;
+use serde::Deserialize;
+
+use crate::retention::PrunedDataError;
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[cfg_attr(test, derive(Copy, Clone))]
+#[serde(deny_unknown_fields)]
+pub struct TraceBlockTransactionsInput {
+    block_id: BlockId,
+}
+
+crate::error::generate_rpc_error_subset!(TraceBlockTransactionsError: BlockNotFound, PrunedData);
+
+impl From<PrunedDataError> for TraceBlockTransactionsError {
+    fn from(_: PrunedDataError) -> Self {
+        Self::PrunedData
+    }
+}
+
+/// Trace every transaction in a block.
+///
+/// Tracing re-executes against historical state, so it requires
+/// `RetentionPolicy::Full` (see [`crate::retention`]). Under
+/// `RetentionPolicy::HeadersOnly` it errors for every `block_id`, whether or
+/// not the state needed for it has been pruned yet.
+pub async fn trace_block_transactions(
+    context: RpcContext,
+    input: TraceBlockTransactionsInput,
+) -> Result<types::BlockTransactionTraces, TraceBlockTransactionsError> {
+    context.retention_policy.ensure_bodies_available()?;
+
+    let _ = input;
+    // Execution itself is unaffected by the retention-policy routing added
+    // here and isn't part of this slice.
+    todo!("block transaction tracing")
+}
+
+mod types {
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct BlockTransactionTraces(pub Vec<serde_json::Value>);
+}
@@ -0,0 +1,46 @@
+use crate::context::RpcContext;
+
+use pathfinder_common::TransactionHash;
+use serde::Deserialize;
+
+use crate::retention::PrunedDataError;
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[cfg_attr(test, derive(Copy, Clone))]
+#[serde(deny_unknown_fields)]
+pub struct TraceTransactionInput {
+    transaction_hash: TransactionHash,
+}
+
+crate::error::generate_rpc_error_subset!(TraceTransactionError: TxnHashNotFound, PrunedData);
+
+impl From<PrunedDataError> for TraceTransactionError {
+    fn from(_: PrunedDataError) -> Self {
+        Self::PrunedData
+    }
+}
+
+/// Trace a single transaction's execution.
+///
+/// Tracing re-executes against historical state, so it requires
+/// `RetentionPolicy::Full` (see [`crate::retention`]). Under
+/// `RetentionPolicy::HeadersOnly` it errors for every transaction, whether
+/// or not the state needed to replay it has been pruned yet.
+pub async fn trace_transaction(
+    context: RpcContext,
+    input: TraceTransactionInput,
+) -> Result<types::TransactionTrace, TraceTransactionError> {
+    context.retention_policy.ensure_bodies_available()?;
+
+    let _ = input;
+    // Execution itself is unaffected by the retention-policy routing added
+    // here and isn't part of this slice.
+    todo!("transaction tracing")
+}
+
+mod types {
+    use serde::Serialize;
+
+    #[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+    pub struct TransactionTrace(pub serde_json::Value);
+}
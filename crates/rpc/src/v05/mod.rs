@@ -0,0 +1,100 @@
+mod method;
+pub(crate) mod types;
+
+use jsonrpsee::RpcModule;
+
+use crate::context::RpcContext;
+
+/// Registers every JSON-RPC method exposed by the `v0.5` API under its
+/// spec-defined name, plus the Pathfinder-specific `pathfinder_*` extensions.
+pub fn register_routes(context: RpcContext) -> anyhow::Result<RpcModule<RpcContext>> {
+    let mut module = RpcModule::new(context);
+
+    module.register_async_method(
+        "starknet_getBlockWithTxHashes",
+        |params, context| async move {
+            let input = params.parse()?;
+            method::get_block_with_tx_hashes((*context).clone(), input)
+                .await
+                .map_err(Into::into)
+        },
+    )?;
+    module.register_async_method("starknet_getBlockWithTxs", |params, context| async move {
+        let input = params.parse()?;
+        method::get_block_with_txs((*context).clone(), input)
+            .await
+            .map_err(Into::into)
+    })?;
+    module.register_async_method(
+        "starknet_getTransactionReceipt",
+        |params, context| async move {
+            let input = params.parse()?;
+            method::get_transaction_receipt((*context).clone(), input)
+                .await
+                .map_err(Into::into)
+        },
+    )?;
+    module.register_async_method(
+        "starknet_getTransactionStatus",
+        |params, context| async move {
+            let input = params.parse()?;
+            method::get_transaction_status((*context).clone(), input)
+                .await
+                .map_err(Into::into)
+        },
+    )?;
+    module.register_async_method(
+        "starknet_simulateTransactions",
+        |params, context| async move {
+            let input = params.parse()?;
+            method::simulate_transactions((*context).clone(), input)
+                .await
+                .map_err(Into::into)
+        },
+    )?;
+    module.register_async_method("starknet_specVersion", |_params, context| async move {
+        method::spec_version((*context).clone())
+            .await
+            .map_err(Into::into)
+    })?;
+    module.register_async_method(
+        "starknet_traceBlockTransactions",
+        |params, context| async move {
+            let input = params.parse()?;
+            method::trace_block_transactions((*context).clone(), input)
+                .await
+                .map_err(Into::into)
+        },
+    )?;
+    module.register_async_method("starknet_traceTransaction", |params, context| async move {
+        let input = params.parse()?;
+        method::trace_transaction((*context).clone(), input)
+            .await
+            .map_err(Into::into)
+    })?;
+
+    // Pathfinder-specific extensions.
+    module.register_async_method("pathfinder_getHeaderProof", |params, context| async move {
+        let input = params.parse()?;
+        method::get_header_proof((*context).clone(), input)
+            .await
+            .map_err(Into::into)
+    })?;
+    module.register_async_method("pathfinder_getSectionRoots", |params, context| async move {
+        let input = params.parse()?;
+        method::get_section_roots((*context).clone(), input)
+            .await
+            .map_err(Into::into)
+    })?;
+    module.register_async_method(
+        "pathfinder_getFinalityStatus",
+        |params, context| async move {
+            let input = params.parse()?;
+            method::get_finality_status((*context).clone(), input)
+                .await
+                .map_err(Into::into)
+        },
+    )?;
+
+    Ok(module)
+}
@@ -0,0 +1,106 @@
+//! Storage for cached Canonical-Hash-Trie (CHT) section roots.
+//!
+//! Only *complete* sections ever get a row here: callers must only
+//! [`Transaction::upsert_cht_section_root`] a section once every leaf slot
+//! in it corresponds to an existing block. The still-open tip section's
+//! root changes with every new block and is always recomputed by the RPC
+//! layer rather than cached.
+//!
+//! Rows are invalidated on reorg via
+//! [`Transaction::delete_cht_section_roots_from`], called by
+//! [`crate::purge_reorged_cht_sections`].
+
+use pathfinder_crypto::Felt;
+use rusqlite::OptionalExtension;
+
+use crate::connection::Transaction;
+
+/// Creates the section-root cache table.
+pub(crate) const MIGRATION: &str = r"
+CREATE TABLE IF NOT EXISTS cht_section_roots (
+    section_number INTEGER NOT NULL PRIMARY KEY,
+    root           BLOB    NOT NULL
+)";
+
+impl Transaction<'_> {
+    /// Reads a previously cached section root, if any.
+    pub fn cht_section_root(&self, section: u64) -> anyhow::Result<Option<Felt>> {
+        self.inner
+            .query_row(
+                "SELECT root FROM cht_section_roots WHERE section_number = ?1",
+                rusqlite::params![section],
+                |row| row.get::<_, Vec<u8>>(0),
+            )
+            .optional()?
+            .map(|bytes| Felt::from_be_slice(&bytes).map_err(Into::into))
+            .transpose()
+    }
+
+    /// Caches `root` as the root of `section`, overwriting any previous
+    /// value. Callers must only do this for complete sections.
+    pub fn upsert_cht_section_root(&self, section: u64, root: Felt) -> anyhow::Result<()> {
+        self.inner.execute(
+            "INSERT INTO cht_section_roots (section_number, root) VALUES (?1, ?2)
+             ON CONFLICT (section_number) DO UPDATE SET root = excluded.root",
+            rusqlite::params![section, root.as_be_bytes().to_vec()],
+        )?;
+        Ok(())
+    }
+
+    /// Deletes cached roots for `section` and every section after it, since
+    /// a reorg that invalidates `section` also invalidates anything built
+    /// on top of it.
+    pub fn delete_cht_section_roots_from(&self, section: u64) -> anyhow::Result<()> {
+        self.inner.execute(
+            "DELETE FROM cht_section_roots WHERE section_number >= ?1",
+            rusqlite::params![section],
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::Connection;
+
+    #[test]
+    fn uncached_section_reads_as_none() {
+        let mut connection = Connection::for_testing().unwrap();
+        let transaction = connection.transaction().unwrap();
+
+        assert_eq!(transaction.cht_section_root(0).unwrap(), None);
+    }
+
+    #[test]
+    fn upsert_then_read_round_trips() {
+        let mut connection = Connection::for_testing().unwrap();
+        let transaction = connection.transaction().unwrap();
+        let root = Felt::from_be_slice(&1u64.to_be_bytes()).unwrap();
+
+        transaction.upsert_cht_section_root(0, root).unwrap();
+        assert_eq!(transaction.cht_section_root(0).unwrap(), Some(root));
+
+        // Upserting again overwrites rather than erroring on the existing row.
+        let other_root = Felt::from_be_slice(&2u64.to_be_bytes()).unwrap();
+        transaction.upsert_cht_section_root(0, other_root).unwrap();
+        assert_eq!(transaction.cht_section_root(0).unwrap(), Some(other_root));
+    }
+
+    #[test]
+    fn delete_from_purges_section_and_everything_after_it() {
+        let mut connection = Connection::for_testing().unwrap();
+        let transaction = connection.transaction().unwrap();
+        let root = Felt::from_be_slice(&1u64.to_be_bytes()).unwrap();
+
+        for section in 0..3 {
+            transaction.upsert_cht_section_root(section, root).unwrap();
+        }
+
+        transaction.delete_cht_section_roots_from(1).unwrap();
+
+        assert_eq!(transaction.cht_section_root(0).unwrap(), Some(root));
+        assert_eq!(transaction.cht_section_root(1).unwrap(), None);
+        assert_eq!(transaction.cht_section_root(2).unwrap(), None);
+    }
+}
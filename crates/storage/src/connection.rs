@@ -0,0 +1,50 @@
+//! Database connection and transaction handles.
+
+/// A handle to the pathfinder database file.
+pub struct Storage {
+    path: std::path::PathBuf,
+}
+
+impl Storage {
+    pub fn connection(&self) -> anyhow::Result<Connection> {
+        Ok(Connection {
+            inner: rusqlite::Connection::open(&self.path)?,
+        })
+    }
+}
+
+/// A single connection, checked out of [`Storage`].
+pub struct Connection {
+    inner: rusqlite::Connection,
+}
+
+impl Connection {
+    pub fn transaction(&mut self) -> anyhow::Result<Transaction<'_>> {
+        Ok(Transaction {
+            inner: self.inner.transaction()?,
+        })
+    }
+
+    /// An in-memory connection with the CHT/L1-finality migrations applied,
+    /// for tests in this crate or downstream crates that only need those
+    /// tables. The rest of the schema lives elsewhere and isn't created
+    /// here, so this isn't suitable for exercising accessors outside this
+    /// module's scope (`block_header`, `block_is_l1_accepted`, ...).
+    ///
+    /// The pre-existing `l1_accepted` table that [`crate::l1_finality`]'s
+    /// migration extends is itself created elsewhere; a minimal stand-in is
+    /// created here so that migration has something to `ALTER`.
+    pub fn for_testing() -> anyhow::Result<Connection> {
+        let inner = rusqlite::Connection::open_in_memory()?;
+        inner.execute_batch("CREATE TABLE l1_accepted (block_number INTEGER PRIMARY KEY)")?;
+        for migration in crate::migrations::MIGRATIONS {
+            inner.execute_batch(migration)?;
+        }
+        Ok(Connection { inner })
+    }
+}
+
+/// A single read/write unit of work against the database.
+pub struct Transaction<'a> {
+    pub(crate) inner: rusqlite::Transaction<'a>,
+}
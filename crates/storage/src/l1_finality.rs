@@ -0,0 +1,143 @@
+//! L1 finality tracking: which L1 transaction and block accepted each L2
+//! block. Extends the previously boolean `block_is_l1_accepted` check with
+//! the settlement-layer coordinates needed to surface a precise finality
+//! signal instead of a binary flag.
+
+use pathfinder_common::{BlockNumber, EthereumBlockNumber, EthereumTransactionHash, L1Finality};
+use rusqlite::OptionalExtension;
+
+use crate::connection::Transaction;
+
+/// Extends the existing L1-acceptance table with the coordinates of the
+/// accepting transaction. Existing rows get `NULL` until the node observes
+/// the corresponding L1 state update; callers that only need the
+/// acceptance status (not the coordinates) should use
+/// [`Transaction::is_l1_accepted`], which falls back to the legacy flag for
+/// rows that predate these columns.
+pub(crate) const MIGRATION: &str = r"
+ALTER TABLE l1_accepted ADD COLUMN l1_block_number INTEGER;
+ALTER TABLE l1_accepted ADD COLUMN l1_transaction_hash BLOB;
+";
+
+impl Transaction<'_> {
+    /// Returns the L1 finality data for `number`, or `None` if the node
+    /// doesn't have the L1 coordinates for this block — either because it
+    /// hasn't been accepted on L1 yet, or because it was accepted before
+    /// this node learned to record the coordinates (see
+    /// [`Self::is_l1_accepted`] for a status check that doesn't have this
+    /// gap).
+    pub fn l1_finality_for_block(
+        &self,
+        number: BlockNumber,
+    ) -> anyhow::Result<Option<L1Finality>> {
+        self.inner
+            .query_row(
+                "SELECT l1_block_number, l1_transaction_hash FROM l1_accepted \
+                 WHERE block_number = ?1",
+                rusqlite::params![number.get()],
+                |row| {
+                    Ok((
+                        row.get::<_, Option<u64>>(0)?,
+                        row.get::<_, Option<Vec<u8>>>(1)?,
+                    ))
+                },
+            )
+            .optional()?
+            .and_then(|(l1_block_number, tx_hash_bytes)| {
+                Some((l1_block_number?, tx_hash_bytes?))
+            })
+            .map(|(l1_block_number, tx_hash_bytes)| {
+                Ok(L1Finality {
+                    l1_block_number: EthereumBlockNumber(l1_block_number),
+                    l1_transaction_hash: EthereumTransactionHash::from_be_slice(&tx_hash_bytes)?,
+                })
+            })
+            .transpose()
+    }
+
+    /// Whether `number` has been accepted on L1, without requiring the L1
+    /// coordinates this node may not have recorded.
+    ///
+    /// Rows written before the `l1_block_number`/`l1_transaction_hash`
+    /// columns existed carry `NULL` in both, so [`Self::l1_finality_for_block`]
+    /// alone would report such blocks as still `AcceptedOnL2` even though
+    /// they were already finalized on L1 — this falls back to the legacy
+    /// acceptance flag for exactly that case.
+    pub fn is_l1_accepted(&self, number: BlockNumber) -> anyhow::Result<bool> {
+        Ok(self.l1_finality_for_block(number)?.is_some()
+            || self.block_is_l1_accepted(number.into())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::Connection;
+
+    #[test]
+    fn unaccepted_block_has_no_finality() {
+        let mut connection = Connection::for_testing().unwrap();
+        let transaction = connection.transaction().unwrap();
+
+        assert_eq!(
+            transaction
+                .l1_finality_for_block(BlockNumber::GENESIS)
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn accepted_block_reports_its_l1_coordinates() {
+        let mut connection = Connection::for_testing().unwrap();
+        let transaction = connection.transaction().unwrap();
+
+        let raw_tx_hash = vec![0xabu8; 32];
+        transaction
+            .inner
+            .execute(
+                "INSERT INTO l1_accepted (block_number, l1_block_number, l1_transaction_hash) \
+                 VALUES (?1, ?2, ?3)",
+                rusqlite::params![BlockNumber::GENESIS.get(), 123u64, raw_tx_hash.clone()],
+            )
+            .unwrap();
+
+        let finality = transaction
+            .l1_finality_for_block(BlockNumber::GENESIS)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(finality.l1_block_number, EthereumBlockNumber(123));
+        assert_eq!(
+            finality.l1_transaction_hash,
+            EthereumTransactionHash::from_be_slice(&raw_tx_hash).unwrap()
+        );
+    }
+
+    #[test]
+    fn legacy_row_without_coordinates_has_no_finality() {
+        // A row written before the l1_block_number/l1_transaction_hash
+        // columns existed: block_number is set, but the new columns are
+        // NULL. `l1_finality_for_block` can't report coordinates for it.
+        // `is_l1_accepted` answers for such rows from the legacy
+        // `block_is_l1_accepted` flag, which `Connection::for_testing`
+        // doesn't support, so only the finality lookup is checked here.
+        let mut connection = Connection::for_testing().unwrap();
+        let transaction = connection.transaction().unwrap();
+
+        transaction
+            .inner
+            .execute(
+                "INSERT INTO l1_accepted (block_number) VALUES (?1)",
+                rusqlite::params![BlockNumber::GENESIS.get()],
+            )
+            .unwrap();
+
+        assert_eq!(
+            transaction
+                .l1_finality_for_block(BlockNumber::GENESIS)
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,17 @@
+//! Pathfinder's SQLite-backed storage layer.
+//!
+//! This crate carries the `Connection`/`Transaction` handles plus the
+//! accessors and migrations needed for the Canonical-Hash-Trie (CHT)
+//! section-root cache and L1-finality tracking. The rest of the accessor
+//! surface (`block_header`, `block_is_l1_accepted`,
+//! `transaction_hashes_for_block`, ...) lives alongside these and is
+//! unaffected by this module.
+
+mod cht;
+mod connection;
+mod l1_finality;
+mod migrations;
+mod reorg;
+
+pub use connection::{Connection, Storage, Transaction};
+pub use reorg::purge_reorged_cht_sections;
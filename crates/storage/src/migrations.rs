@@ -0,0 +1,6 @@
+//! Schema migrations, applied in order against a fresh or existing
+//! database. Only the migrations introduced alongside the CHT and
+//! L1-finality work are listed here; they are appended to the end of the
+//! existing (much longer) migration list.
+
+pub(crate) const MIGRATIONS: &[&str] = &[crate::cht::MIGRATION, crate::l1_finality::MIGRATION];
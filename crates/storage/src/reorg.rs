@@ -0,0 +1,44 @@
+//! Hooks invoked by the chain-reorg handler to keep cached derived data
+//! consistent with the canonical chain.
+
+use crate::connection::Transaction;
+
+/// Purges cached CHT section roots that could have been computed over
+/// leaves in the reverted range. Invoked via
+/// `pathfinder_rpc::cht::invalidate_reorged_sections`, which the sync
+/// pipeline's reorg handler must call alongside the existing
+/// header/body/state purges whenever the canonical chain is reverted and
+/// blocks from `first_invalid_section` onward are no longer canonical.
+///
+/// `first_invalid_section` is the lowest section index touched by the
+/// reorg, i.e. `pathfinder_rpc::cht::section_index(first_invalid_block)`.
+/// It's passed in rather than recomputed here so that this crate doesn't
+/// need to know the RPC layer's section size.
+pub fn purge_reorged_cht_sections(
+    transaction: &Transaction<'_>,
+    first_invalid_section: u64,
+) -> anyhow::Result<()> {
+    transaction.delete_cht_section_roots_from(first_invalid_section)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::Connection;
+    use pathfinder_crypto::Felt;
+
+    #[test]
+    fn purge_delegates_to_delete_from() {
+        let mut connection = Connection::for_testing().unwrap();
+        let transaction = connection.transaction().unwrap();
+        let root = Felt::from_be_slice(&1u64.to_be_bytes()).unwrap();
+
+        transaction.upsert_cht_section_root(0, root).unwrap();
+        transaction.upsert_cht_section_root(1, root).unwrap();
+
+        purge_reorged_cht_sections(&transaction, 1).unwrap();
+
+        assert_eq!(transaction.cht_section_root(0).unwrap(), Some(root));
+        assert_eq!(transaction.cht_section_root(1).unwrap(), None);
+    }
+}